@@ -0,0 +1,54 @@
+use crate::config::KeyConfig;
+
+pub struct CommandText {
+    pub name: String,
+}
+
+impl CommandText {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+pub struct CommandInfo {
+    pub text: CommandText,
+}
+
+impl CommandInfo {
+    pub fn new(text: CommandText) -> Self {
+        Self { text }
+    }
+}
+
+// These tabs are no longer reachable by a direct per-tab key — only by
+// cycling with `toggle_property_tabs`/`toggle_property_tabs_reverse` (see
+// their CommandText below) — so the labels carry no key hint of their own.
+pub fn tab_new_user(_key_config: &KeyConfig) -> CommandText {
+    CommandText::new("New User".to_string())
+}
+
+pub fn tab_del_user(_key_config: &KeyConfig) -> CommandText {
+    CommandText::new("Del User".to_string())
+}
+
+pub fn tab_new_graph(_key_config: &KeyConfig) -> CommandText {
+    CommandText::new("New Graph".to_string())
+}
+
+pub fn tab_del_graph(_key_config: &KeyConfig) -> CommandText {
+    CommandText::new("Del Graph".to_string())
+}
+
+pub fn toggle_property_tabs(key_config: &KeyConfig) -> CommandText {
+    CommandText::new(format!(
+        "Cycle tabs [{:?}]",
+        key_config.toggle_property_tabs
+    ))
+}
+
+pub fn toggle_property_tabs_reverse(key_config: &KeyConfig) -> CommandText {
+    CommandText::new(format!(
+        "Cycle tabs reverse [{:?}]",
+        key_config.toggle_property_tabs_reverse
+    ))
+}
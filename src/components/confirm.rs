@@ -0,0 +1,51 @@
+use super::StatefulDrawableComponent;
+use crate::event::Key;
+use anyhow::Result;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// A modal guarding a destructive statement behind an explicit yes/no.
+pub struct ConfirmComponent {
+    statement: String,
+}
+
+impl ConfirmComponent {
+    pub fn new(statement: impl Into<String>) -> Self {
+        Self {
+            statement: statement.into(),
+        }
+    }
+
+    pub fn statement(&self) -> &str {
+        &self.statement
+    }
+
+    /// Returns `Some(true)` on confirm, `Some(false)` on cancel, `None` if the
+    /// key didn't match either.
+    pub fn event(&mut self, key: Key) -> Result<Option<bool>> {
+        match key {
+            Key::Char('y') => Ok(Some(true)),
+            Key::Char('n') | Key::Esc => Ok(Some(false)),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl StatefulDrawableComponent for ConfirmComponent {
+    fn draw<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect, _focused: bool) -> Result<()> {
+        let text = format!("{}\n\n[y] confirm    [n] cancel", self.statement);
+        let paragraph = Paragraph::new(text).wrap(Wrap { trim: true }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Confirm")
+                .style(Style::default().fg(Color::Red)),
+        );
+        f.render_widget(paragraph, area);
+        Ok(())
+    }
+}
@@ -1,9 +1,11 @@
 use super::{Component, EventState, StatefulDrawableComponent};
 use crate::clipboard::copy_to_clipboard;
 use crate::components::command::{self, CommandInfo};
+use crate::components::confirm::ConfirmComponent;
+use crate::components::user_form::UserFormComponent;
 use crate::components::TableComponent;
 use crate::config::KeyConfig;
-use crate::database::Pool;
+use crate::database::{Dialect, Pool};
 use crate::event::Key;
 use anyhow::Result;
 use async_trait::async_trait;
@@ -16,13 +18,12 @@ use tui::{
     Frame,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Focus {
     NewUser,
     DelUser,
     NewGraph,
     DelGraph,
-    // TODO add others
 }
 
 impl std::fmt::Display for Focus {
@@ -31,35 +32,142 @@ impl std::fmt::Display for Focus {
     }
 }
 
-pub struct OperationsComponent {
+/// A user/graph mutation queued up by a form or list selection, waiting to be
+/// sent through the `Pool` once the surrounding app drives it to completion.
+pub enum PendingAction {
+    CreateUser {
+        username: String,
+        password: String,
+        host: String,
+        privileges: Vec<String>,
+    },
+    DropUser {
+        username: String,
+        host: String,
+    },
+    DropIndex {
+        table: String,
+        index: String,
+    },
+}
+
+impl PendingAction {
+    /// The statement this action boils down to, shown in the confirmation
+    /// modal. Mirrors the per-`dialect` syntax that `Pool::create_user`/
+    /// `drop_user`/`drop_index` actually execute, so the modal never shows
+    /// a statement that wouldn't run against the connected backend.
+    fn statement(&self, dialect: Dialect) -> String {
+        match self {
+            Self::CreateUser {
+                username, host, ..
+            } => match dialect {
+                Dialect::MySql => format!("CREATE USER '{}'@'{}' ...", username, host),
+                Dialect::Postgres => format!("CREATE ROLE \"{}\" WITH LOGIN PASSWORD '...'", username),
+                Dialect::Sqlite => "-- unsupported: SQLite has no server-level users".to_string(),
+            },
+            Self::DropUser { username, host } => match dialect {
+                Dialect::MySql => format!("DROP USER '{}'@'{}'", username, host),
+                Dialect::Postgres => format!("DROP ROLE \"{}\"", username),
+                Dialect::Sqlite => "-- unsupported: SQLite has no server-level users".to_string(),
+            },
+            Self::DropIndex { table, index } => match dialect {
+                Dialect::MySql => format!("DROP INDEX `{}` ON `{}`", index, table),
+                Dialect::Postgres => format!("DROP INDEX \"{}\"", index),
+                Dialect::Sqlite => format!("DROP INDEX \"{}\"", index),
+            },
+        }
+    }
+
+    fn is_destructive(&self) -> bool {
+        matches!(self, Self::DropUser { .. } | Self::DropIndex { .. })
+    }
+}
 
-    new_user: TableComponent,
+pub struct OperationsComponent {
+    new_user: UserFormComponent,
     del_user: TableComponent,
     new_graph: TableComponent,
     del_graph: TableComponent,
     // TODO add other operations
     focus: Focus,
     key_config: KeyConfig,
+    pending_action: Option<PendingAction>,
+    pending_confirm_action: Option<PendingAction>,
+    confirm: Option<ConfirmComponent>,
+    last_affected_rows: Option<u64>,
+    current_table: Option<Table>,
+    dialect: Option<Dialect>,
 }
 
 impl OperationsComponent {
     pub fn new(key_config: KeyConfig) -> Self {
         Self {
-            new_user: TableComponent::new(key_config.clone()),
+            new_user: UserFormComponent::new(),
             del_user: TableComponent::new(key_config.clone()),
             new_graph: TableComponent::new(key_config.clone()),
             del_graph: TableComponent::new(key_config.clone()),
             focus: Focus::NewUser,
             key_config,
+            pending_action: None,
+            pending_confirm_action: None,
+            confirm: None,
+            last_affected_rows: None,
+            current_table: None,
+            dialect: None,
+        }
+    }
+
+    /// Takes the affected-row count of the last executed mutation, if any, so
+    /// the caller can surface it on the status line.
+    pub fn take_last_affected_rows(&mut self) -> Option<u64> {
+        self.last_affected_rows.take()
+    }
+
+    /// Drains any queued mutation and runs it through the `Pool`, recording
+    /// the affected-row count for `take_last_affected_rows`. The app's event
+    /// loop must call this right after `event()` so a submit/confirm keypress
+    /// executes immediately, instead of waiting for the next table refresh.
+    pub async fn drain_pending_action(&mut self, pool: &Box<dyn Pool>) -> Result<()> {
+        if let Some(action) = self.pending_action.take() {
+            let affected = match action {
+                PendingAction::CreateUser {
+                    username,
+                    password,
+                    host,
+                    privileges,
+                } => {
+                    pool.create_user(&username, &password, &host, &privileges)
+                        .await?
+                }
+                PendingAction::DropUser { username, host } => {
+                    pool.drop_user(&username, &host).await?
+                }
+                PendingAction::DropIndex { table, index } => {
+                    pool.drop_index(&table, &index).await?
+                }
+            };
+            self.last_affected_rows = Some(affected);
+        }
+        Ok(())
+    }
+
+    /// Queues a mutation for execution, gating destructive ones behind a confirmation modal.
+    fn queue_action(&mut self, action: PendingAction) {
+        if action.is_destructive() {
+            let dialect = self.dialect.unwrap_or(Dialect::MySql);
+            self.confirm = Some(ConfirmComponent::new(action.statement(dialect)));
+            self.pending_confirm_action = Some(action);
+        } else {
+            self.pending_action = Some(action);
         }
     }
 
-    fn focused_component(&mut self) -> &mut TableComponent {
+    fn focused_table(&mut self) -> &mut TableComponent {
         match self.focus {
-            Focus::NewUser => &mut self.new_user,
             Focus::DelUser => &mut self.del_user,
             Focus::NewGraph => &mut self.new_graph,
             Focus::DelGraph => &mut self.del_graph,
+            Focus::NewUser => unreachable!("NewUser is backed by the input form, not a table"),
         }
     }
 
@@ -69,29 +177,24 @@ impl OperationsComponent {
         table: Table,
         pool: &Box<dyn Pool>,
     ) -> Result<()> {
+        self.current_table = Some(table.clone());
+        self.dialect = Some(pool.dialect());
+
         self.new_user.reset();
-        let columns = pool.get_columns(&database, &table).await?;
-        if !columns.is_empty() {
 
-            self.new_user.update(
-                columns
-                    .iter()
-                    .map(|c| c.columns())
-                    .collect::<Vec<Vec<String>>>(),
-                columns.get(0).unwrap().fields(),
-                database.clone(),
-                table.clone(),
-            );
-        }
         self.del_user.reset();
-        let constraints = pool.get_constraints(&database, &table).await?;
-        if !constraints.is_empty() {
+        let users = pool.list_users().await?;
+        if !users.is_empty() {
+            // Rendered as MySQL's own `user@host` account-identifier syntax
+            // so the row selected in the confirm modal is unambiguous even
+            // when a username exists at more than one host; parsed back
+            // apart in the `DelUser` Enter handler below.
             self.del_user.update(
-                constraints
+                users
                     .iter()
-                    .map(|c| c.columns())
+                    .map(|(username, host)| vec![format!("{}@{}", username, host)])
                     .collect::<Vec<Vec<String>>>(),
-                constraints.get(0).unwrap().fields(),
+                vec!["User".to_string()],
                 database.clone(),
                 table.clone(),
             );
@@ -139,6 +242,25 @@ impl OperationsComponent {
             (Focus::DelGraph, command::tab_del_graph(&self.key_config).name),
         ]
     }
+
+    /// Advances `self.focus` to the next (or, if `reverse`, previous) tab in
+    /// `tab_names()` order, wrapping around. Adding a new `Focus` variant to
+    /// `tab_names()` makes it reachable here automatically.
+    fn cycle_focus(&mut self, reverse: bool) {
+        let order = self
+            .tab_names()
+            .into_iter()
+            .map(|(focus, _)| focus)
+            .collect::<Vec<Focus>>();
+        let len = order.len();
+        let current = order.iter().position(|f| *f == self.focus).unwrap_or(0);
+        let next = if reverse {
+            (current + len - 1) % len
+        } else {
+            (current + 1) % len
+        };
+        self.focus = order[next];
+    }
 }
 
 impl StatefulDrawableComponent for OperationsComponent {
@@ -170,7 +292,15 @@ impl StatefulDrawableComponent for OperationsComponent {
 
         f.render_widget(tab_list, layout[0]);
 
-        self.focused_component().draw(f, layout[1], focused)?;
+        if self.focus == Focus::NewUser {
+            self.new_user.draw(f, layout[1], focused)?;
+        } else {
+            self.focused_table().draw(f, layout[1], focused)?;
+        }
+
+        if let Some(confirm) = self.confirm.as_mut() {
+            confirm.draw(f, layout[1], focused)?;
+        }
         Ok(())
     }
 }
@@ -181,24 +311,105 @@ impl Component for OperationsComponent {
         out.push(CommandInfo::new(command::toggle_property_tabs(
             &self.key_config,
         )));
+        out.push(CommandInfo::new(command::toggle_property_tabs_reverse(
+            &self.key_config,
+        )));
     }
 
     fn event(&mut self, key: Key) -> Result<EventState> {
-        self.focused_component().event(key)?;
+        if let Some(confirm) = self.confirm.as_mut() {
+            match confirm.event(key)? {
+                Some(true) => self.pending_action = self.pending_confirm_action.take(),
+                Some(false) => self.pending_confirm_action = None,
+                None => return Ok(EventState::Consumed),
+            }
+            self.confirm = None;
+            return Ok(EventState::Consumed);
+        }
+
+        if self.focus == Focus::NewUser {
+            self.new_user.event(key)?;
+        } else {
+            self.focused_table().event(key)?;
+        }
 
         if key == self.key_config.copy {
-            if let Some(text) = self.focused_component().selected_cells() {
-                copy_to_clipboard(text.as_str())?
+            if self.focus != Focus::NewUser {
+                if let Some(text) = self.focused_table().selected_cells() {
+                    copy_to_clipboard(text.as_str())?
+                }
             }
-        } else if key == self.key_config.tab_columns {
-            self.focus = Focus::NewUser;
-        } else if key == self.key_config.tab_constraints {
-            self.focus = Focus::DelUser;
-        } else if key == self.key_config.tab_foreign_keys {
-            self.focus = Focus::NewGraph;
-        } else if key == self.key_config.tab_indexes {
-            self.focus = Focus::DelGraph;
+        } else if key == Key::Enter {
+            match self.focus {
+                Focus::NewUser => {
+                    if self.new_user.is_valid() {
+                        self.queue_action(PendingAction::CreateUser {
+                            username: self.new_user.username.clone(),
+                            password: self.new_user.password.clone(),
+                            host: self.new_user.host.clone(),
+                            privileges: self.new_user.privilege_list(),
+                        });
+                        self.new_user.reset();
+                    }
+                }
+                Focus::DelUser => {
+                    if let Some(account) = self.del_user.selected_cells() {
+                        if let Some((username, host)) = account.rsplit_once('@') {
+                            self.queue_action(PendingAction::DropUser {
+                                username: username.to_string(),
+                                host: host.to_string(),
+                            });
+                        }
+                    }
+                }
+                Focus::DelGraph => {
+                    if let (Some(index), Some(table)) =
+                        (self.del_graph.selected_cells(), self.current_table.as_ref())
+                    {
+                        self.queue_action(PendingAction::DropIndex {
+                            table: table.name.clone(),
+                            index,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        } else if key == self.key_config.toggle_property_tabs {
+            self.cycle_focus(false);
+        } else if key == self.key_config.toggle_property_tabs_reverse {
+            self.cycle_focus(true);
         }
         Ok(EventState::NotConsumed)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle_focus_wraps_forward() {
+        let mut component = OperationsComponent::new(KeyConfig::default());
+        assert_eq!(component.focus, Focus::NewUser);
+
+        component.cycle_focus(false);
+        assert_eq!(component.focus, Focus::DelUser);
+        component.cycle_focus(false);
+        assert_eq!(component.focus, Focus::NewGraph);
+        component.cycle_focus(false);
+        assert_eq!(component.focus, Focus::DelGraph);
+        component.cycle_focus(false);
+        assert_eq!(component.focus, Focus::NewUser);
+    }
+
+    #[test]
+    fn cycle_focus_wraps_backward() {
+        let mut component = OperationsComponent::new(KeyConfig::default());
+        assert_eq!(component.focus, Focus::NewUser);
+
+        component.cycle_focus(true);
+        assert_eq!(component.focus, Focus::DelGraph);
+        component.cycle_focus(true);
+        assert_eq!(component.focus, Focus::NewGraph);
+    }
+}
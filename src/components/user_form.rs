@@ -0,0 +1,213 @@
+use super::{EventState, StatefulDrawableComponent};
+use crate::event::Key;
+use anyhow::Result;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum UserFormField {
+    Username,
+    Password,
+    Host,
+    Privileges,
+}
+
+impl UserFormField {
+    fn next(self) -> Self {
+        match self {
+            Self::Username => Self::Password,
+            Self::Password => Self::Host,
+            Self::Host => Self::Privileges,
+            Self::Privileges => Self::Username,
+        }
+    }
+}
+
+/// Collects the fields needed to create a user (`NewUser`), one character at a time.
+pub struct UserFormComponent {
+    pub username: String,
+    pub password: String,
+    pub host: String,
+    pub privileges: String,
+    focus: UserFormField,
+}
+
+impl UserFormComponent {
+    pub fn new() -> Self {
+        Self {
+            username: String::new(),
+            password: String::new(),
+            host: "%".to_string(),
+            privileges: String::new(),
+            focus: UserFormField::Username,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.username.clear();
+        self.password.clear();
+        self.host = "%".to_string();
+        self.privileges.clear();
+        self.focus = UserFormField::Username;
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.username.is_empty() && !self.password.is_empty()
+    }
+
+    pub fn privilege_list(&self) -> Vec<String> {
+        self.privileges
+            .split(',')
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect()
+    }
+
+    fn field_mut(&mut self) -> &mut String {
+        match self.focus {
+            UserFormField::Username => &mut self.username,
+            UserFormField::Password => &mut self.password,
+            UserFormField::Host => &mut self.host,
+            UserFormField::Privileges => &mut self.privileges,
+        }
+    }
+
+    pub fn event(&mut self, key: Key) -> Result<EventState> {
+        match key {
+            Key::Tab => self.focus = self.focus.next(),
+            Key::Backspace => {
+                self.field_mut().pop();
+            }
+            Key::Char(c) => self.field_mut().push(c),
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+}
+
+impl StatefulDrawableComponent for UserFormComponent {
+    fn draw<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect, focused: bool) -> Result<()> {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+            ])
+            .split(area);
+
+        let fields = [
+            (UserFormField::Username, "Username", self.username.clone()),
+            (
+                UserFormField::Password,
+                "Password",
+                "*".repeat(self.password.len()),
+            ),
+            (UserFormField::Host, "Host", self.host.clone()),
+            (
+                UserFormField::Privileges,
+                "Privileges (comma separated)",
+                self.privileges.clone(),
+            ),
+        ];
+
+        for (i, (field, title, value)) in fields.into_iter().enumerate() {
+            let is_focused = focused && field == self.focus;
+            let paragraph = Paragraph::new(value).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .style(if is_focused {
+                        Style::default().fg(Color::Blue)
+                    } else {
+                        Style::default()
+                    }),
+            );
+            f.render_widget(paragraph, layout[i]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tab_routes_typed_chars_through_each_field_in_order() {
+        let mut form = UserFormComponent::new();
+        form.event(Key::Char('a')).unwrap();
+        assert_eq!(form.username, "a");
+
+        form.event(Key::Tab).unwrap();
+        form.event(Key::Char('b')).unwrap();
+        assert_eq!(form.password, "b");
+
+        form.event(Key::Tab).unwrap();
+        form.event(Key::Char('c')).unwrap();
+        assert_eq!(form.host, "%c");
+
+        form.event(Key::Tab).unwrap();
+        form.event(Key::Char('d')).unwrap();
+        assert_eq!(form.privileges, "d");
+
+        // Wraps back around to username.
+        form.event(Key::Tab).unwrap();
+        form.event(Key::Char('!')).unwrap();
+        assert_eq!(form.username, "a!");
+    }
+
+    #[test]
+    fn backspace_pops_the_focused_field() {
+        let mut form = UserFormComponent::new();
+        form.event(Key::Char('a')).unwrap();
+        form.event(Key::Char('b')).unwrap();
+        form.event(Key::Backspace).unwrap();
+        assert_eq!(form.username, "a");
+    }
+
+    #[test]
+    fn is_valid_requires_username_and_password() {
+        let mut form = UserFormComponent::new();
+        assert!(!form.is_valid());
+
+        form.username = "admin".to_string();
+        assert!(!form.is_valid());
+
+        form.password = "hunter2".to_string();
+        assert!(form.is_valid());
+    }
+
+    #[test]
+    fn privilege_list_splits_trims_and_drops_empties() {
+        let mut form = UserFormComponent::new();
+        form.privileges = " SELECT, INSERT ,, DROP".to_string();
+        assert_eq!(
+            form.privilege_list(),
+            vec!["SELECT".to_string(), "INSERT".to_string(), "DROP".to_string()]
+        );
+    }
+
+    #[test]
+    fn reset_clears_fields_and_refocuses_username() {
+        let mut form = UserFormComponent::new();
+        form.event(Key::Char('a')).unwrap();
+        form.event(Key::Tab).unwrap();
+        form.event(Key::Char('b')).unwrap();
+
+        form.reset();
+        assert_eq!(form.username, "");
+        assert_eq!(form.password, "");
+        assert_eq!(form.host, "%");
+        assert_eq!(form.privileges, "");
+
+        form.event(Key::Char('z')).unwrap();
+        assert_eq!(form.username, "z");
+    }
+}
@@ -0,0 +1,26 @@
+use crate::event::Key;
+
+#[derive(Clone)]
+pub struct KeyConfig {
+    pub copy: Key,
+    pub tab_columns: Key,
+    pub tab_constraints: Key,
+    pub tab_foreign_keys: Key,
+    pub tab_indexes: Key,
+    pub toggle_property_tabs: Key,
+    pub toggle_property_tabs_reverse: Key,
+}
+
+impl Default for KeyConfig {
+    fn default() -> Self {
+        Self {
+            copy: Key::Char('y'),
+            tab_columns: Key::Char('1'),
+            tab_constraints: Key::Char('2'),
+            tab_foreign_keys: Key::Char('3'),
+            tab_indexes: Key::Char('4'),
+            toggle_property_tabs: Key::Tab,
+            toggle_property_tabs_reverse: Key::Char('R'),
+        }
+    }
+}
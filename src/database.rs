@@ -0,0 +1,189 @@
+mod mysql;
+mod postgres;
+mod sqlite;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use database_tree::{Database, Table};
+
+/// Which SQL dialect a `Pool` speaks, so callers that can't `await` (e.g. a
+/// synchronous `Component::event()` building confirmation text) can still
+/// render backend-correct statements via `Pool::dialect`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    MySql,
+    Postgres,
+    Sqlite,
+}
+
+#[async_trait]
+pub trait Pool: Send + Sync {
+    /// The SQL dialect this pool speaks, used to render confirmation text
+    /// that matches what `create_user`/`drop_user`/`drop_index` will actually run.
+    fn dialect(&self) -> Dialect;
+
+    async fn get_columns(&self, database: &Database, table: &Table) -> Result<Vec<Box<dyn TableRow>>>;
+
+    async fn get_constraints(
+        &self,
+        database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn TableRow>>>;
+
+    async fn get_foreign_keys(
+        &self,
+        database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn TableRow>>>;
+
+    async fn get_indexes(&self, database: &Database, table: &Table) -> Result<Vec<Box<dyn TableRow>>>;
+
+    /// Executes an arbitrary DDL/DML statement against the backing server and
+    /// returns the number of rows it affected.
+    async fn execute(&self, sql: &str) -> Result<u64>;
+
+    /// Lists the `(username, host)` pairs known to the server, used to
+    /// populate the `DelUser` tab. The host is required so a selected row
+    /// can be dropped unambiguously: the same username may exist at more
+    /// than one host.
+    async fn list_users(&self) -> Result<Vec<(String, String)>>;
+
+    /// Creates a user and grants it the given privileges. Built on top of `execute`,
+    /// so backends only need to implement `execute` to get this for free.
+    ///
+    /// MySQL-flavored by default (`'user'@'host'`); Postgres/SQLite override this.
+    async fn create_user(
+        &self,
+        username: &str,
+        password: &str,
+        host: &str,
+        privileges: &[String],
+    ) -> Result<u64> {
+        let privileges = validate_privileges(privileges)?;
+        self.execute(&format!(
+            "CREATE USER '{}'@'{}' IDENTIFIED BY '{}'",
+            escape_mysql_literal(username),
+            escape_mysql_literal(host),
+            escape_mysql_literal(password)
+        ))
+        .await?;
+        if privileges.is_empty() {
+            return Ok(1);
+        }
+        self.execute(&format!(
+            "GRANT {} ON *.* TO '{}'@'{}'",
+            privileges.join(", "),
+            escape_mysql_literal(username),
+            escape_mysql_literal(host)
+        ))
+        .await
+    }
+
+    /// Drops a user. Built on top of `execute`. MySQL-flavored by default;
+    /// Postgres/SQLite override this.
+    async fn drop_user(&self, username: &str, host: &str) -> Result<u64> {
+        self.execute(&format!(
+            "DROP USER '{}'@'{}'",
+            escape_mysql_literal(username),
+            escape_mysql_literal(host)
+        ))
+        .await
+    }
+
+    /// Drops an index, backing the `DelGraph` tab. Built on top of `execute`;
+    /// MySQL requires the owning table name, unlike Postgres/SQLite.
+    async fn drop_index(&self, table: &str, index: &str) -> Result<u64> {
+        self.execute(&format!(
+            "DROP INDEX `{}` ON `{}`",
+            escape_identifier(index),
+            escape_identifier(table)
+        ))
+        .await
+    }
+}
+
+pub trait TableRow: Send + Sync {
+    fn fields(&self) -> Vec<String>;
+    fn columns(&self) -> Vec<String>;
+}
+
+/// A row of metadata (one column, constraint, foreign key, or index) as
+/// returned by the `get_*` introspection queries, keyed by field name.
+pub struct MetadataRow {
+    field_names: Vec<String>,
+    values: Vec<String>,
+}
+
+impl MetadataRow {
+    pub fn new(field_names: Vec<String>, values: Vec<String>) -> Self {
+        Self {
+            field_names,
+            values,
+        }
+    }
+}
+
+impl TableRow for MetadataRow {
+    fn fields(&self) -> Vec<String> {
+        self.field_names.clone()
+    }
+
+    fn columns(&self) -> Vec<String> {
+        self.values.clone()
+    }
+}
+
+/// Escapes a string so it's safe to embed as a single-quoted ANSI SQL literal
+/// (used by Postgres, which runs with `standard_conforming_strings` on and
+/// treats `\` as an ordinary character).
+pub(crate) fn escape_literal(value: &str) -> String {
+    value.replace('\'', "''")
+}
+
+/// Escapes a string so it's safe to embed as a single-quoted MySQL literal.
+/// Unlike ANSI SQL, MySQL's default `sql_mode` treats `\` as an escape
+/// character inside string literals, so backslashes must be escaped first —
+/// otherwise a value ending in `\` swallows the closing quote.
+pub(crate) fn escape_mysql_literal(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+/// Escapes a string so it's safe to embed as a double/backtick-quoted identifier.
+pub(crate) fn escape_identifier(identifier: &str) -> String {
+    identifier.replace('`', "``").replace('"', "\"\"")
+}
+
+/// Privilege names are SQL keywords, not values, so they can't be escaped as
+/// literals — instead they're checked against an allow-list before being
+/// spliced into a `GRANT` statement.
+const ALLOWED_PRIVILEGES: &[&str] = &[
+    "ALL",
+    "ALL PRIVILEGES",
+    "SELECT",
+    "INSERT",
+    "UPDATE",
+    "DELETE",
+    "CREATE",
+    "DROP",
+    "ALTER",
+    "INDEX",
+    "REFERENCES",
+    "TRIGGER",
+    "EXECUTE",
+    "USAGE",
+    "GRANT OPTION",
+];
+
+pub(crate) fn validate_privileges(privileges: &[String]) -> Result<Vec<String>> {
+    privileges
+        .iter()
+        .map(|privilege| {
+            let normalized = privilege.trim().to_uppercase();
+            if ALLOWED_PRIVILEGES.contains(&normalized.as_str()) {
+                Ok(normalized)
+            } else {
+                Err(anyhow::anyhow!("unknown privilege: {}", privilege))
+            }
+        })
+        .collect()
+}
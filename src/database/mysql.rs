@@ -0,0 +1,137 @@
+use super::{Dialect, MetadataRow, Pool};
+use anyhow::Result;
+use async_trait::async_trait;
+use database_tree::{Database, Table};
+use sqlx::mysql::MySqlPool as SqlxMySqlPool;
+use sqlx::Row;
+
+pub struct MySqlPool {
+    pool: SqlxMySqlPool,
+}
+
+async fn fetch_metadata(
+    pool: &SqlxMySqlPool,
+    sql: &str,
+    schema: &str,
+    table: &str,
+    field_names: Vec<String>,
+) -> Result<Vec<Box<dyn super::TableRow>>> {
+    let rows = sqlx::query(sql)
+        .bind(schema)
+        .bind(table)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let values = (0..field_names.len())
+                .map(|i| row.get::<String, _>(i))
+                .collect();
+            Box::new(MetadataRow::new(field_names.clone(), values)) as Box<dyn super::TableRow>
+        })
+        .collect())
+}
+
+#[async_trait]
+impl Pool for MySqlPool {
+    fn dialect(&self) -> Dialect {
+        Dialect::MySql
+    }
+
+    async fn get_columns(
+        &self,
+        database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_metadata(
+            &self.pool,
+            "SELECT column_name, column_type, is_nullable, column_key, column_default, extra \
+             FROM information_schema.columns WHERE table_schema = ? AND table_name = ? \
+             ORDER BY ordinal_position",
+            &database.name,
+            &table.name,
+            vec![
+                "Name".to_string(),
+                "Type".to_string(),
+                "Null".to_string(),
+                "Key".to_string(),
+                "Default".to_string(),
+                "Extra".to_string(),
+            ],
+        )
+        .await
+    }
+
+    async fn get_constraints(
+        &self,
+        database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_metadata(
+            &self.pool,
+            "SELECT constraint_name, constraint_type \
+             FROM information_schema.table_constraints WHERE table_schema = ? AND table_name = ?",
+            &database.name,
+            &table.name,
+            vec!["Name".to_string(), "Type".to_string()],
+        )
+        .await
+    }
+
+    async fn get_foreign_keys(
+        &self,
+        database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_metadata(
+            &self.pool,
+            "SELECT constraint_name, column_name, referenced_table_name, referenced_column_name \
+             FROM information_schema.key_column_usage \
+             WHERE table_schema = ? AND table_name = ? AND referenced_table_name IS NOT NULL",
+            &database.name,
+            &table.name,
+            vec![
+                "Name".to_string(),
+                "Column".to_string(),
+                "Ref Table".to_string(),
+                "Ref Column".to_string(),
+            ],
+        )
+        .await
+    }
+
+    async fn get_indexes(
+        &self,
+        database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_metadata(
+            &self.pool,
+            "SELECT index_name, column_name, non_unique \
+             FROM information_schema.statistics WHERE table_schema = ? AND table_name = ?",
+            &database.name,
+            &table.name,
+            vec![
+                "Name".to_string(),
+                "Column".to_string(),
+                "Non Unique".to_string(),
+            ],
+        )
+        .await
+    }
+
+    async fn execute(&self, sql: &str) -> Result<u64> {
+        let result = sqlx::query(sql).execute(&self.pool).await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn list_users(&self) -> Result<Vec<(String, String)>> {
+        let rows = sqlx::query("SELECT User, Host FROM mysql.user")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows
+            .iter()
+            .map(|row| (row.get::<String, _>("User"), row.get::<String, _>("Host")))
+            .collect())
+    }
+}
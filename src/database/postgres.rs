@@ -0,0 +1,167 @@
+use super::{escape_identifier, escape_literal, validate_privileges, Dialect, MetadataRow, Pool};
+use anyhow::Result;
+use async_trait::async_trait;
+use database_tree::{Database, Table};
+use sqlx::postgres::PgPool;
+use sqlx::Row;
+
+pub struct PostgresPool {
+    pool: PgPool,
+}
+
+async fn fetch_metadata(
+    pool: &PgPool,
+    sql: &str,
+    schema: &str,
+    table: &str,
+    field_names: Vec<String>,
+) -> Result<Vec<Box<dyn super::TableRow>>> {
+    let rows = sqlx::query(sql)
+        .bind(schema)
+        .bind(table)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let values = (0..field_names.len())
+                .map(|i| row.get::<String, _>(i))
+                .collect();
+            Box::new(MetadataRow::new(field_names.clone(), values)) as Box<dyn super::TableRow>
+        })
+        .collect())
+}
+
+#[async_trait]
+impl Pool for PostgresPool {
+    fn dialect(&self) -> Dialect {
+        Dialect::Postgres
+    }
+
+    async fn get_columns(
+        &self,
+        database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_metadata(
+            &self.pool,
+            "SELECT column_name, data_type, is_nullable, column_default \
+             FROM information_schema.columns WHERE table_schema = $1 AND table_name = $2 \
+             ORDER BY ordinal_position",
+            &database.name,
+            &table.name,
+            vec![
+                "Name".to_string(),
+                "Type".to_string(),
+                "Null".to_string(),
+                "Default".to_string(),
+            ],
+        )
+        .await
+    }
+
+    async fn get_constraints(
+        &self,
+        database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_metadata(
+            &self.pool,
+            "SELECT constraint_name, constraint_type \
+             FROM information_schema.table_constraints WHERE table_schema = $1 AND table_name = $2",
+            &database.name,
+            &table.name,
+            vec!["Name".to_string(), "Type".to_string()],
+        )
+        .await
+    }
+
+    async fn get_foreign_keys(
+        &self,
+        database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_metadata(
+            &self.pool,
+            "SELECT constraint_name, column_name, referenced_table_name, referenced_column_name \
+             FROM information_schema.key_column_usage \
+             WHERE table_schema = $1 AND table_name = $2 AND referenced_table_name IS NOT NULL",
+            &database.name,
+            &table.name,
+            vec![
+                "Name".to_string(),
+                "Column".to_string(),
+                "Ref Table".to_string(),
+                "Ref Column".to_string(),
+            ],
+        )
+        .await
+    }
+
+    async fn get_indexes(
+        &self,
+        database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_metadata(
+            &self.pool,
+            "SELECT indexname, indexdef FROM pg_indexes WHERE schemaname = $1 AND tablename = $2",
+            &database.name,
+            &table.name,
+            vec!["Name".to_string(), "Definition".to_string()],
+        )
+        .await
+    }
+
+    async fn execute(&self, sql: &str) -> Result<u64> {
+        let result = sqlx::query(sql).execute(&self.pool).await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn list_users(&self) -> Result<Vec<(String, String)>> {
+        // Postgres roles aren't host-scoped, unlike MySQL users; `drop_user`
+        // ignores its `host` argument here, so an empty string is reported.
+        let rows = sqlx::query("SELECT rolname FROM pg_roles WHERE rolcanlogin")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows
+            .iter()
+            .map(|row| (row.get::<String, _>("rolname"), String::new()))
+            .collect())
+    }
+
+    async fn create_user(
+        &self,
+        username: &str,
+        password: &str,
+        _host: &str,
+        privileges: &[String],
+    ) -> Result<u64> {
+        let privileges = validate_privileges(privileges)?;
+        self.execute(&format!(
+            "CREATE ROLE \"{}\" WITH LOGIN PASSWORD '{}'",
+            escape_identifier(username),
+            escape_literal(password)
+        ))
+        .await?;
+        if privileges.is_empty() {
+            return Ok(1);
+        }
+        self.execute(&format!(
+            "GRANT {} ON ALL TABLES IN SCHEMA public TO \"{}\"",
+            privileges.join(", "),
+            escape_identifier(username)
+        ))
+        .await
+    }
+
+    async fn drop_user(&self, username: &str, _host: &str) -> Result<u64> {
+        self.execute(&format!("DROP ROLE \"{}\"", escape_identifier(username)))
+            .await
+    }
+
+    async fn drop_index(&self, _table: &str, index: &str) -> Result<u64> {
+        self.execute(&format!("DROP INDEX \"{}\"", escape_identifier(index)))
+            .await
+    }
+}
@@ -0,0 +1,151 @@
+use super::{escape_identifier, Dialect, MetadataRow, Pool};
+use anyhow::Result;
+use async_trait::async_trait;
+use database_tree::{Database, Table};
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+
+pub struct SqlitePoolWrapper {
+    pool: SqlitePool,
+}
+
+async fn fetch_pragma(
+    pool: &SqlitePool,
+    pragma: &str,
+    table: &str,
+    field_names: Vec<String>,
+) -> Result<Vec<Box<dyn super::TableRow>>> {
+    // PRAGMA statements don't accept bind parameters, so the table name is
+    // interpolated after identifier-escaping instead.
+    let sql = format!("PRAGMA {}(\"{}\")", pragma, escape_identifier(table));
+    let rows = sqlx::query(&sql).fetch_all(pool).await?;
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let values = (0..field_names.len())
+                .map(|i| row.try_get::<String, _>(i).unwrap_or_default())
+                .collect();
+            Box::new(MetadataRow::new(field_names.clone(), values)) as Box<dyn super::TableRow>
+        })
+        .collect())
+}
+
+#[async_trait]
+impl Pool for SqlitePoolWrapper {
+    fn dialect(&self) -> Dialect {
+        Dialect::Sqlite
+    }
+
+    async fn get_columns(
+        &self,
+        _database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_pragma(
+            &self.pool,
+            "table_info",
+            &table.name,
+            vec![
+                "cid".to_string(),
+                "Name".to_string(),
+                "Type".to_string(),
+                "notnull".to_string(),
+                "Default".to_string(),
+                "pk".to_string(),
+            ],
+        )
+        .await
+    }
+
+    async fn get_constraints(
+        &self,
+        _database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_pragma(
+            &self.pool,
+            "index_list",
+            &table.name,
+            vec![
+                "seq".to_string(),
+                "Name".to_string(),
+                "unique".to_string(),
+                "origin".to_string(),
+                "partial".to_string(),
+            ],
+        )
+        .await
+    }
+
+    async fn get_foreign_keys(
+        &self,
+        _database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_pragma(
+            &self.pool,
+            "foreign_key_list",
+            &table.name,
+            vec![
+                "id".to_string(),
+                "seq".to_string(),
+                "Ref Table".to_string(),
+                "Column".to_string(),
+                "Ref Column".to_string(),
+                "on_update".to_string(),
+                "on_delete".to_string(),
+                "match".to_string(),
+            ],
+        )
+        .await
+    }
+
+    async fn get_indexes(
+        &self,
+        _database: &Database,
+        table: &Table,
+    ) -> Result<Vec<Box<dyn super::TableRow>>> {
+        fetch_pragma(
+            &self.pool,
+            "index_list",
+            &table.name,
+            vec![
+                "seq".to_string(),
+                "Name".to_string(),
+                "unique".to_string(),
+                "origin".to_string(),
+                "partial".to_string(),
+            ],
+        )
+        .await
+    }
+
+    async fn execute(&self, sql: &str) -> Result<u64> {
+        let result = sqlx::query(sql).execute(&self.pool).await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn list_users(&self) -> Result<Vec<(String, String)>> {
+        // SQLite has no server-level user concept, so there is nothing to list.
+        Ok(Vec::new())
+    }
+
+    async fn create_user(
+        &self,
+        _username: &str,
+        _password: &str,
+        _host: &str,
+        _privileges: &[String],
+    ) -> Result<u64> {
+        anyhow::bail!("SQLite has no server-level users; create_user is not supported")
+    }
+
+    async fn drop_user(&self, _username: &str, _host: &str) -> Result<u64> {
+        anyhow::bail!("SQLite has no server-level users; drop_user is not supported")
+    }
+
+    async fn drop_index(&self, _table: &str, index: &str) -> Result<u64> {
+        self.execute(&format!("DROP INDEX \"{}\"", escape_identifier(index)))
+            .await
+    }
+}